@@ -80,6 +80,11 @@ impl Batch {
     pub fn trace(&self) -> bool {
         self.trace
     }
+
+    /// Consumes the `Batch` and returns its transactions, in batch order.
+    pub fn into_transactions(self) -> Vec<Transaction> {
+        self.transactions
+    }
 }
 
 pub struct BatchPair {
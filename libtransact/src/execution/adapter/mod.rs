@@ -49,14 +49,52 @@ pub trait ExecutionAdapter: Send {
     fn stop(self: Box<Self>) -> bool;
 }
 
+/// A family-independent category for why a transaction was invalid.
+///
+/// Unlike `error_message` and `error_data`, the error code can be aggregated without knowing
+/// anything about the transaction family.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InvalidTransactionErrorCode {
+    /// The reason was not categorized.
+    #[default]
+    Unknown,
+    /// The transaction or its batch had a bad signature.
+    InvalidSignature,
+    /// A transaction that this transaction depends on was not valid.
+    MissingDependency,
+    /// The transaction could not be applied to the current state.
+    StateError,
+}
+
 /// An `InvalidTransaction` has information about why the transaction failed.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InvalidTransaction {
     /// human readable reason for why the transaction was invalid.
     pub error_message: String,
     /// Transaction specific data that is returned to the client
     /// who submitted the Transaction.
     pub error_data: Vec<u8>,
+    /// Machine-readable category for the failure, `Unknown` unless set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub error_code: InvalidTransactionErrorCode,
+}
+
+impl InvalidTransaction {
+    /// Creates an `InvalidTransaction` with an `Unknown` error code.
+    pub fn new(error_message: String, error_data: Vec<u8>) -> Self {
+        InvalidTransaction {
+            error_message,
+            error_data,
+            error_code: InvalidTransactionErrorCode::default(),
+        }
+    }
+
+    pub fn with_error_code(mut self, error_code: InvalidTransactionErrorCode) -> Self {
+        self.error_code = error_code;
+        self
+    }
 }
 
 /// The outcome of a transaction's execution.
@@ -64,14 +102,135 @@ pub struct InvalidTransaction {
 /// A `TransactionStatus` covers the possible outcomes that can occur during a
 /// transaction's execution.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransactionStatus {
     Invalid(InvalidTransaction),
     Valid,
 }
 
 /// The `ExecutionResult` provides the status for a given transaction.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExecutionResult {
     pub transaction_id: String,
     pub status: TransactionStatus,
 }
+
+impl ExecutionResult {
+    /// Returns true if the transaction was valid.
+    pub fn is_valid(&self) -> bool {
+        self.status == TransactionStatus::Valid
+    }
+
+    /// Returns the id of the transaction this result is for.
+    pub fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TRANSACTION_ID1: &str = "one";
+    static TRANSACTION_ID2: &str = "two";
+    static TRANSACTION_ID3: &str = "three";
+    static BYTES1: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    fn valid_result(transaction_id: &str) -> ExecutionResult {
+        ExecutionResult {
+            transaction_id: transaction_id.to_string(),
+            status: TransactionStatus::Valid,
+        }
+    }
+
+    fn invalid_result(transaction_id: &str) -> ExecutionResult {
+        ExecutionResult {
+            transaction_id: transaction_id.to_string(),
+            status: TransactionStatus::Invalid(InvalidTransaction::new(
+                "invalid".to_string(),
+                BYTES1.to_vec(),
+            )),
+        }
+    }
+
+    #[test]
+    fn execution_results_mixed_validity() {
+        let results = [
+            valid_result(TRANSACTION_ID1),
+            invalid_result(TRANSACTION_ID2),
+            valid_result(TRANSACTION_ID3),
+        ];
+
+        assert_eq!(
+            vec![true, false, true],
+            results
+                .iter()
+                .map(ExecutionResult::is_valid)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![TRANSACTION_ID1, TRANSACTION_ID2, TRANSACTION_ID3],
+            results
+                .iter()
+                .map(ExecutionResult::transaction_id)
+                .collect::<Vec<_>>()
+        );
+        assert!(!results.iter().all(ExecutionResult::is_valid));
+    }
+
+    #[test]
+    fn execution_results_all_valid() {
+        let results = [valid_result(TRANSACTION_ID1), valid_result(TRANSACTION_ID2)];
+
+        assert!(results.iter().all(ExecutionResult::is_valid));
+        assert_eq!(TRANSACTION_ID2, results[1].transaction_id());
+    }
+
+    #[test]
+    fn invalid_transaction_error_code() {
+        let invalid = InvalidTransaction::new("invalid".to_string(), BYTES1.to_vec());
+        assert_eq!(InvalidTransactionErrorCode::Unknown, invalid.error_code);
+
+        let invalid = invalid.with_error_code(InvalidTransactionErrorCode::MissingDependency);
+        assert_eq!(
+            InvalidTransactionErrorCode::MissingDependency,
+            invalid.error_code
+        );
+        assert_eq!("invalid", invalid.error_message);
+        assert_eq!(BYTES1.to_vec(), invalid.error_data);
+    }
+
+    /// Serializes a valid and an invalid `ExecutionResult` to JSON and back, and checks that the
+    /// results are unchanged. The `error_data` bytes are serialized as an array of numbers.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn execution_result_serde_round_trip() {
+        let results = vec![
+            valid_result(TRANSACTION_ID1),
+            invalid_result(TRANSACTION_ID2),
+        ];
+
+        let json = serde_json::to_string(&results).expect("Unable to serialize results");
+        assert!(json.contains("[1,2,3,4]"));
+
+        let deserialized: Vec<ExecutionResult> =
+            serde_json::from_str(&json).expect("Unable to deserialize results");
+        assert_eq!(results, deserialized);
+    }
+
+    /// An `InvalidTransaction` serialized before the error code was added deserializes with an
+    /// `Unknown` error code.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn invalid_transaction_serde_default_error_code() {
+        let json = r#"{"error_message":"invalid","error_data":[1,2,3,4]}"#;
+
+        let invalid: InvalidTransaction =
+            serde_json::from_str(json).expect("Unable to deserialize invalid transaction");
+        assert_eq!(
+            InvalidTransaction::new("invalid".to_string(), BYTES1.to_vec()),
+            invalid
+        );
+    }
+}
@@ -28,7 +28,7 @@
 use crate::execution::adapter::{ExecutionAdapter, ExecutionAdapterError, ExecutionResult};
 use crate::execution::{ExecutionRegistry, TransactionFamily};
 use crate::scheduler::ExecutionTask;
-use log::warn;
+use log::{debug, warn};
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::hash::{Hash, Hasher};
@@ -241,6 +241,7 @@ impl ExecuterThread {
                     match execution_command {
                         ExecutionCommand::Event(execution_event) => {
                             let (results_sender, task) = *execution_event;
+                            let trace = task.trace();
                             let (pair, context_id) = task.take();
 
                             let callback = Box::new(move |result| {
@@ -260,7 +261,8 @@ impl ExecuterThread {
                                         let sender = sender.clone();
 
                                         let execution_task =
-                                            ExecutionTask::new(transaction_pair, context_id);
+                                            ExecutionTask::new(transaction_pair, context_id)
+                                                .with_trace(trace);
                                         let execution_event = (res_sender, execution_task);
                                         if let Err(err) =
                                             sender.send(RegistrationExecutionEvent::Execution(
@@ -274,7 +276,8 @@ impl ExecuterThread {
                                         let sender = sender.clone();
 
                                         let execution_task =
-                                            ExecutionTask::new(transaction_pair, context_id);
+                                            ExecutionTask::new(transaction_pair, context_id)
+                                                .with_trace(trace);
                                         let execution_event = (res_sender, execution_task);
                                         if let Err(err) =
                                             sender.send(RegistrationExecutionEvent::Execution(
@@ -286,6 +289,12 @@ impl ExecuterThread {
                                     }
                                 }
                             });
+                            if trace {
+                                debug!(
+                                    "Executing traced transaction {}",
+                                    pair.transaction().header_signature()
+                                );
+                            }
                             execution_adapter.execute(pair, context_id, callback);
                         }
                         ExecutionCommand::Sentinel => {
@@ -657,6 +666,57 @@ mod tests {
         executer_thread.stop();
     }
 
+    /// Sends a traced task to an execution adapter thread whose adapter has no transaction
+    /// family registered, so the adapter returns a `RoutingError`. The task that the thread
+    /// re-registers for retry is still traced.
+    #[test]
+    fn test_retry_keeps_trace() {
+        let stop = Arc::new(AtomicBool::new(false));
+        let (registration_execution_event_sender, internal_receiver) =
+            channel::<RegistrationExecutionEvent>();
+        let (execution_adapter_sender, receiver) = channel::<ExecutionCommand>();
+
+        let join_handle = ExecuterThread::start_execution_adapter_thread(
+            stop,
+            Box::new(TestExecutionAdapter::new()),
+            receiver,
+            &registration_execution_event_sender,
+            0,
+        )
+        .expect("Unable to start the execution adapter thread");
+
+        let signer = HashSigner::new();
+        let context_id = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let pair = create_txn(&signer);
+        let transaction_id = pair.transaction().header_signature().to_string();
+        let task = ExecutionTask::new(pair, context_id).with_trace(true);
+
+        let (sender, _result_receiver) = channel::<ExecutionResult>();
+        execution_adapter_sender
+            .send(ExecutionCommand::Event(Box::new((sender, task))))
+            .expect("The receiver has been dropped");
+
+        match internal_receiver.recv_timeout(Duration::from_millis(1000)) {
+            Ok(RegistrationExecutionEvent::Execution(execution_event)) => {
+                let (_, task) = execution_event.as_ref();
+                assert!(task.trace(), "The retried task is no longer traced");
+                assert_eq!(&context_id, task.context_id());
+                assert_eq!(transaction_id, task.pair().transaction().header_signature());
+            }
+            Ok(RegistrationExecutionEvent::RegistrationChange(_)) => {
+                panic!("Expected the task to be retried, got a registration change")
+            }
+            Err(err) => panic!("The task was not retried: {}", err),
+        }
+
+        execution_adapter_sender
+            .send(ExecutionCommand::Sentinel)
+            .expect("The receiver has been dropped");
+        join_handle
+            .join()
+            .expect("The execution adapter thread panicked");
+    }
+
     fn create_txn(signer: &Signer) -> TransactionPair {
         TransactionBuilder::new()
             .with_batcher_public_key(hex::decode(KEY1).unwrap())
@@ -27,6 +27,7 @@ use std::error::Error as StdError;
 /// values in state.  This covers the setting of a key/value pair, or the
 /// deletion of a key.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StateChange<K, V> {
     Set { key: K, value: V },
     Delete { key: K },
@@ -100,6 +101,7 @@ impl IntoNative<StateChange<String, Vec<u8>>> for protos::transaction_receipt::S
 
 /// A `TransactionReceipt` has the state changes associated with a valid transaction.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransactionReceipt<K, V> {
     /// Updates to state that were generated by the transaction.
     pub state_changes: Vec<StateChange<K, V>>,
@@ -177,6 +179,7 @@ impl IntoNative<TransactionReceipt<String, Vec<u8>>>
 /// transmitted by `transact` but are not verified or saved to state. `Event`s are
 /// generated by any of the ways that `Transaction`s are processed in `transact`.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Event {
     /// A human readable string that identifies this event
     /// Determined by the client.
@@ -490,6 +493,33 @@ mod tests {
         check_transaction_receipt(transaction_receipt)
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn transaction_receipt_serde_round_trip() {
+        let transaction_receipt = TransactionReceipt {
+            state_changes: vec![
+                StateChange::Set {
+                    key: ADDRESS.to_string(),
+                    value: BYTES1.to_vec(),
+                },
+                StateChange::Delete {
+                    key: ADDRESS.to_string(),
+                },
+            ],
+            events: vec![make_event_1(), make_event_2()],
+            data: vec![BYTES1.to_vec(), BYTES2.to_vec(), BYTES3.to_vec()],
+            transaction_id: TRANSACTION_ID.to_string(),
+        };
+
+        let json = serde_json::to_string(&transaction_receipt)
+            .expect("Unable to serialize transaction receipt");
+        let deserialized: TransactionReceipt<String, Vec<u8>> =
+            serde_json::from_str(&json).expect("Unable to deserialize transaction receipt");
+
+        assert_eq!(TRANSACTION_ID, deserialized.transaction_id);
+        check_transaction_receipt(deserialized)
+    }
+
     fn check_transaction_receipt(transaction_receipt: TransactionReceipt<String, Vec<u8>>) {
         for state_change in transaction_receipt.state_changes {
             check_state_change(state_change)
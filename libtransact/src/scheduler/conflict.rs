@@ -0,0 +1,122 @@
+/*
+ * Copyright 2019 Bitwise IO, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+//! Functions for deciding whether two transactions can be executed concurrently, based on
+//! the input and output addresses they declare.
+
+/// Returns true if two transactions conflict, given their declared inputs and outputs.
+///
+/// Two transactions conflict if either one writes to an address that the other reads from
+/// or writes to. Reading from the same address does not cause a conflict. Addresses are
+/// compared by prefix, so a partial address such as `"abcdef"` overlaps every address that
+/// starts with it.
+///
+/// Addresses may be hex strings or the decoded bytes held in a `TransactionHeader`, such as
+/// those returned by `ExecutionTask::input_addresses`. All four sets must use the same
+/// encoding.
+pub fn addresses_conflict<A: AsRef<[u8]>>(
+    a_inputs: &[A],
+    a_outputs: &[A],
+    b_inputs: &[A],
+    b_outputs: &[A],
+) -> bool {
+    any_overlap(a_outputs, b_inputs)
+        || any_overlap(a_outputs, b_outputs)
+        || any_overlap(b_outputs, a_inputs)
+}
+
+/// Returns true if any address in `a` is a prefix of an address in `b`, or vice versa.
+fn any_overlap<A: AsRef<[u8]>>(a: &[A], b: &[A]) -> bool {
+    a.iter().any(|a_address| {
+        b.iter()
+            .any(|b_address| prefixes_overlap(a_address.as_ref(), b_address.as_ref()))
+    })
+}
+
+fn prefixes_overlap(a: &[u8], b: &[u8]) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ADDRESS1: &str =
+        "5b7349700e158b598043efd6d7610345a75a00b22ac14c9278db53f586179a92b72fbd";
+    static ADDRESS2: &str =
+        "5b7349c6a1fdd8b4d3cabc16bdec1fc5f3e5c1a29c6452d1ecf36d8ea16e2cd3f1ba2d";
+    static ADDRESS3: &str =
+        "1cf1266e282c41be5e4254d8820772c5518a2c5a8c0c7f7eda19594a7eb539453e1ed7";
+
+    fn addresses(addresses: &[&str]) -> Vec<String> {
+        addresses.iter().map(|a| a.to_string()).collect()
+    }
+
+    /// Writing to an address that the other transaction reads or writes is a conflict, in
+    /// either direction.
+    #[test]
+    fn conflict_exact_match() {
+        let read = addresses(&[ADDRESS1]);
+        let write = addresses(&[ADDRESS1]);
+
+        assert!(addresses_conflict(&[], &write, &read, &[]));
+        assert!(addresses_conflict(&read, &[], &[], &write));
+        assert!(addresses_conflict(&[], &write, &[], &write));
+    }
+
+    /// A partial address conflicts with the full addresses that start with it.
+    #[test]
+    fn conflict_prefix_match() {
+        let prefix = addresses(&[&ADDRESS1[0..6]]);
+        let full = addresses(&[ADDRESS1]);
+
+        assert!(addresses_conflict(&[], &prefix, &full, &[]));
+        assert!(addresses_conflict(&full, &[], &[], &prefix));
+        assert!(addresses_conflict(&[], &full, &[], &prefix));
+
+        // ADDRESS1 and ADDRESS2 share a six character namespace prefix
+        let namespace = addresses(&[&ADDRESS2[0..6]]);
+        assert!(addresses_conflict(&[], &namespace, &full, &[]));
+    }
+
+    /// Decoded address bytes are compared by prefix in the same way as hex strings.
+    #[test]
+    fn conflict_decoded_bytes() {
+        let prefix = vec![hex::decode(&ADDRESS1[0..6]).unwrap()];
+        let full = vec![hex::decode(ADDRESS1).unwrap()];
+        let other = vec![hex::decode(ADDRESS3).unwrap()];
+
+        assert!(addresses_conflict(&[], &prefix, &full, &[]));
+        assert!(!addresses_conflict(&[], &prefix, &other, &[]));
+    }
+
+    #[test]
+    fn no_conflict_read_read() {
+        let read = addresses(&[ADDRESS1]);
+
+        assert!(!addresses_conflict(&read, &[], &read, &[]));
+    }
+
+    #[test]
+    fn no_conflict_disjoint() {
+        let a = addresses(&[ADDRESS1]);
+        let b = addresses(&[ADDRESS3]);
+
+        assert!(!addresses_conflict(&a, &a, &b, &b));
+        assert!(!addresses_conflict(&b, &b, &a, &a));
+    }
+}
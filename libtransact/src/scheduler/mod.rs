@@ -14,10 +14,14 @@
  * limitations under the License.
  * -----------------------------------------------------------------------------
  */
+pub mod conflict;
 pub mod tree;
+pub mod util;
 
+use crate::batch::BatchPair;
 use crate::context::ContextId;
 use crate::execution::adapter::ExecutionResult;
+use crate::protos::ProtoConversionError;
 use crate::transaction::TransactionPair;
 
 /// The `TransactionPair` along with the information needed to execute that
@@ -25,11 +29,43 @@ use crate::transaction::TransactionPair;
 pub struct ExecutionTask {
     pair: TransactionPair,
     context_id: ContextId,
+    trace: bool,
 }
 
 impl ExecutionTask {
     pub fn new(pair: TransactionPair, context_id: ContextId) -> Self {
-        ExecutionTask { pair, context_id }
+        ExecutionTask {
+            pair,
+            context_id,
+            trace: false,
+        }
+    }
+
+    /// Creates a task for each `Transaction` in the batch, in batch order, all executing in
+    /// the given context. Each task carries the batch's trace flag.
+    ///
+    /// The transactions are moved out of the batch rather than cloned.
+    pub fn from_batch(
+        batch: BatchPair,
+        context_id: ContextId,
+    ) -> Result<Vec<ExecutionTask>, ProtoConversionError> {
+        let (batch, _) = batch.take();
+        let trace = batch.trace();
+
+        batch
+            .into_transactions()
+            .into_iter()
+            .map(|transaction| {
+                Ok(ExecutionTask::new(transaction.into_pair()?, context_id).with_trace(trace))
+            })
+            .collect()
+    }
+
+    /// Marks the task for tracing, usually copied from the trace flag of the `Batch`
+    /// that contains the `Transaction`.
+    pub fn with_trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
     }
 
     pub fn pair(&self) -> &TransactionPair {
@@ -40,6 +76,25 @@ impl ExecutionTask {
         &self.context_id
     }
 
+    /// Whether the executor should emit verbose logging while executing this task.
+    pub fn trace(&self) -> bool {
+        self.trace
+    }
+
+    /// The input addresses declared in the `Transaction`'s header, which the executor may
+    /// use to prefetch state.
+    ///
+    /// These are the raw decoded address bytes stored in the header, not hex strings. They
+    /// can be passed directly to `conflict::addresses_conflict`.
+    pub fn input_addresses(&self) -> &[Vec<u8>] {
+        self.pair.header().inputs()
+    }
+
+    /// The output addresses declared in the `Transaction`'s header, as raw decoded bytes.
+    pub fn output_addresses(&self) -> &[Vec<u8>] {
+        self.pair.header().outputs()
+    }
+
     pub fn take(self) -> (TransactionPair, ContextId) {
         (self.pair, self.context_id)
     }
@@ -51,3 +106,115 @@ pub trait SchedulePair: Send {
 
     fn get_schedule_iterator(&self) -> Box<Iterator<Item = ExecutionTask> + Send>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::BatchBuilder;
+    use crate::signing::hash::HashSigner;
+    use crate::transaction::{HashMethod, TransactionBuilder};
+
+    static FAMILY_NAME: &str = "test";
+    static FAMILY_VERSION: &str = "1.0";
+    static BYTES1: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    static KEY1: &str = "111111111111111111111111111111111111111111111111111111111111111111";
+    static KEY2: &str = "222222222222222222222222222222222222222222222222222222222222222222";
+    static KEY3: &str = "333333333333333333333333333333333333333333333333333333333333333333";
+
+    fn create_txn(signer: &HashSigner) -> TransactionPair {
+        create_txn_with_addresses(signer, vec![], vec![])
+    }
+
+    fn create_txn_with_addresses(
+        signer: &HashSigner,
+        inputs: Vec<Vec<u8>>,
+        outputs: Vec<Vec<u8>>,
+    ) -> TransactionPair {
+        TransactionBuilder::new()
+            .with_family_name(FAMILY_NAME.to_string())
+            .with_family_version(FAMILY_VERSION.to_string())
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_payload_hash_method(HashMethod::SHA512)
+            .with_payload(BYTES1.to_vec())
+            .build_pair(signer)
+            .expect("The TransactionBuilder was not given the correct items")
+    }
+
+    #[test]
+    fn execution_task_trace() {
+        let signer = HashSigner::new();
+        let context_id = [0; 16];
+
+        let untraced = ExecutionTask::new(create_txn(&signer), context_id);
+        assert!(!untraced.trace());
+
+        let pairs = vec![create_txn(&signer), create_txn(&signer)];
+        let transactions = pairs
+            .iter()
+            .map(|pair| pair.transaction().clone())
+            .collect::<Vec<_>>();
+
+        let untraced_batch = BatchBuilder::new()
+            .with_transactions(transactions.clone())
+            .build_pair(&signer)
+            .expect("The BatchBuilder was not given the correct items");
+        let traced_batch = BatchBuilder::new()
+            .with_transactions(transactions)
+            .with_trace(true)
+            .build_pair(&signer)
+            .expect("The BatchBuilder was not given the correct items");
+
+        let tasks = ExecutionTask::from_batch(untraced_batch, context_id)
+            .expect("The batch's transaction headers are valid");
+        assert_eq!(2, tasks.len());
+        assert!(tasks.iter().all(|task| !task.trace()));
+
+        let tasks = ExecutionTask::from_batch(traced_batch, context_id)
+            .expect("The batch's transaction headers are valid");
+        assert_eq!(2, tasks.len());
+        for (task, pair) in tasks.iter().zip(pairs.iter()) {
+            assert!(task.trace());
+            assert_eq!(&context_id, task.context_id());
+            assert_eq!(pair.transaction(), task.pair().transaction());
+            assert_eq!(pair.header(), task.pair().header());
+        }
+    }
+
+    #[test]
+    fn execution_task_addresses() {
+        let signer = HashSigner::new();
+        let inputs = vec![
+            hex::decode(KEY1).unwrap(),
+            hex::decode(&KEY2[0..6]).unwrap(),
+        ];
+        let outputs = vec![hex::decode(KEY3).unwrap()];
+
+        let task = ExecutionTask::new(
+            create_txn_with_addresses(&signer, inputs.clone(), outputs.clone()),
+            [0; 16],
+        );
+
+        assert_eq!(inputs, task.input_addresses());
+        assert_eq!(outputs, task.output_addresses());
+
+        // A task that reads KEY3 conflicts with this task, which writes it
+        let reader = ExecutionTask::new(
+            create_txn_with_addresses(&signer, outputs.clone(), vec![]),
+            [0; 16],
+        );
+
+        assert!(conflict::addresses_conflict(
+            task.input_addresses(),
+            task.output_addresses(),
+            reader.input_addresses(),
+            reader.output_addresses(),
+        ));
+        assert!(!conflict::addresses_conflict(
+            task.input_addresses(),
+            &[],
+            reader.input_addresses(),
+            reader.output_addresses(),
+        ));
+    }
+}
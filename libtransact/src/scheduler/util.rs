@@ -0,0 +1,82 @@
+/*
+ * Copyright 2019 Bitwise IO, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+//! Helper functions used when ordering batches for scheduling.
+
+use crate::batch::BatchPair;
+
+/// Returns the number of `Transaction`s in the batch.
+pub fn batch_transaction_count(batch: &BatchPair) -> usize {
+    batch.batch().transactions().len()
+}
+
+/// Sorts batches by their number of `Transaction`s, smallest first.
+///
+/// The sort is stable, so batches of the same size keep their relative order.
+pub fn sort_batches_by_size(batches: &mut [BatchPair]) {
+    batches.sort_by_key(batch_transaction_count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::BatchBuilder;
+    use crate::signing::hash::HashSigner;
+    use crate::transaction::Transaction;
+
+    static BYTES1: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    fn create_batch(signer: &HashSigner, num_transactions: usize) -> BatchPair {
+        let transactions = (0..num_transactions)
+            .map(|i| Transaction::new(BYTES1.to_vec(), hex::encode(vec![i as u8]), vec![]))
+            .collect();
+
+        BatchBuilder::new()
+            .with_transactions(transactions)
+            .build_pair(signer)
+            .expect("The BatchBuilder was not given the correct items")
+    }
+
+    #[test]
+    fn batch_count() {
+        let signer = HashSigner::new();
+
+        assert_eq!(0, batch_transaction_count(&create_batch(&signer, 0)));
+        assert_eq!(3, batch_transaction_count(&create_batch(&signer, 3)));
+    }
+
+    #[test]
+    fn sort_by_size() {
+        let signer = HashSigner::new();
+
+        let mut batches = vec![
+            create_batch(&signer, 3),
+            create_batch(&signer, 1),
+            create_batch(&signer, 2),
+        ];
+
+        sort_batches_by_size(&mut batches);
+
+        assert_eq!(
+            vec![1, 2, 3],
+            batches
+                .iter()
+                .map(batch_transaction_count)
+                .collect::<Vec<_>>()
+        );
+    }
+}
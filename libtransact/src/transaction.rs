@@ -120,12 +120,15 @@ impl FromProto<protos::transaction::TransactionHeader> for TransactionHeader {
 impl FromNative<TransactionHeader> for protos::transaction::TransactionHeader {
     fn from_native(header: TransactionHeader) -> Result<Self, ProtoConversionError> {
         let mut proto_header = protos::transaction::TransactionHeader::new();
+        proto_header.set_family_name(header.family_name().to_string());
         proto_header.set_family_version(header.family_version().to_string());
         proto_header.set_batcher_public_key(hex::encode(header.batcher_public_key()));
         proto_header.set_dependencies(header.dependencies().iter().map(hex::encode).collect());
         proto_header.set_inputs(header.inputs().iter().map(hex::encode).collect());
         proto_header.set_nonce(String::from_utf8(header.nonce().to_vec())?);
         proto_header.set_outputs(header.outputs().iter().map(hex::encode).collect());
+        proto_header.set_payload_sha512(hex::encode(header.payload_hash()));
+        proto_header.set_signer_public_key(hex::encode(header.signer_public_key()));
         Ok(proto_header)
     }
 }
@@ -160,6 +163,19 @@ impl Transaction {
     pub fn payload(&self) -> &[u8] {
         &self.payload
     }
+
+    /// Deserializes the header bytes and returns the `Transaction` paired with its header.
+    pub fn into_pair(self) -> Result<TransactionPair, ProtoConversionError> {
+        let header_proto: protos::transaction::TransactionHeader =
+            protobuf::parse_from_bytes(&self.header)
+                .map_err(|e| ProtoConversionError::SerializationError(format!("{}", e)))?;
+        let header = header_proto.into_native()?;
+
+        Ok(TransactionPair {
+            transaction: self,
+            header,
+        })
+    }
 }
 
 impl From<protos::transaction::Transaction> for Transaction {
@@ -602,6 +618,37 @@ mod tests {
         assert_eq!(hex::decode(KEY8).unwrap(), header.signer_public_key());
     }
 
+    #[test]
+    fn transaction_into_pair() {
+        let signer = HashSigner::new();
+
+        let pair = TransactionBuilder::new()
+            .with_batcher_public_key(hex::decode(KEY1).unwrap())
+            .with_dependencies(vec![hex::decode(KEY2).unwrap(), hex::decode(KEY3).unwrap()])
+            .with_family_name(FAMILY_NAME.to_string())
+            .with_family_version(FAMILY_VERSION.to_string())
+            .with_inputs(vec![
+                hex::decode(KEY4).unwrap(),
+                hex::decode(&KEY5[0..4]).unwrap(),
+            ])
+            .with_nonce(NONCE.to_string().into_bytes())
+            .with_outputs(vec![
+                hex::decode(KEY6).unwrap(),
+                hex::decode(&KEY7[0..4]).unwrap(),
+            ])
+            .with_payload_hash_method(HashMethod::SHA512)
+            .with_payload(BYTES2.to_vec())
+            .build_pair(&signer)
+            .unwrap();
+
+        let (transaction, header) = pair.take();
+        let pair = transaction.clone().into_pair().unwrap();
+
+        assert_eq!(&transaction, pair.transaction());
+        assert_eq!(&header, pair.header());
+        check_builder_transaction(&signer, &pair);
+    }
+
     #[test]
     fn transaction_fields() {
         let transaction = Transaction {